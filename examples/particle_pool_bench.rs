@@ -0,0 +1,66 @@
+//! Measures the cost of the particle ring buffer's steady-state update: despawning the
+//! oldest entity and spawning a new one each frame (the old approach) versus resetting
+//! the oldest entity's `Transform`/`Velocity` in place (the current approach). Run with
+//! `cargo run --release --example particle_pool_bench > bench_output.txt`.
+
+use bevy::prelude::*;
+use heron::prelude::*;
+use std::time::Instant;
+
+const MAX_PARTICLES: usize = 512;
+const FRAMES: usize = 2000;
+
+#[derive(Component)]
+struct Particle;
+
+fn main() {
+    let mut world = World::new();
+    let mut ring: Vec<Entity> = Vec::with_capacity(MAX_PARTICLES);
+
+    let despawn_respawn = Instant::now();
+    for _ in 0..FRAMES {
+        if ring.len() == MAX_PARTICLES {
+            let oldest = ring.remove(0);
+            world.despawn(oldest);
+        }
+        let entity = world
+            .spawn()
+            .insert(Transform::from_xyz(0.0, 40.0, 0.0))
+            .insert(Velocity::from_linear(Vec3::ZERO))
+            .insert(RigidBody::Dynamic)
+            .insert(CollisionShape::Sphere { radius: 0.05 })
+            .insert(Particle)
+            .id();
+        ring.push(entity);
+    }
+    let despawn_respawn_elapsed = despawn_respawn.elapsed();
+
+    world.clear_entities();
+    ring.clear();
+
+    let reset_in_place = Instant::now();
+    for _ in 0..FRAMES {
+        if ring.len() == MAX_PARTICLES {
+            let entity = ring.remove(0);
+            let mut transform = world.get_mut::<Transform>(entity).unwrap();
+            *transform = Transform::from_xyz(0.0, 40.0, 0.0);
+            let mut velocity = world.get_mut::<Velocity>(entity).unwrap();
+            *velocity = Velocity::from_linear(Vec3::ZERO);
+            ring.push(entity);
+        } else {
+            let entity = world
+                .spawn()
+                .insert(Transform::from_xyz(0.0, 40.0, 0.0))
+                .insert(Velocity::from_linear(Vec3::ZERO))
+                .insert(RigidBody::Dynamic)
+                .insert(CollisionShape::Sphere { radius: 0.05 })
+                .insert(Particle)
+                .id();
+            ring.push(entity);
+        }
+    }
+    let reset_in_place_elapsed = reset_in_place.elapsed();
+
+    println!("despawn + respawn ({} frames): {:?}", FRAMES, despawn_respawn_elapsed);
+    println!("reset in place ({} frames):    {:?}", FRAMES, reset_in_place_elapsed);
+}
@@ -1,7 +1,20 @@
-use bevy::{ecs::system::EntityCommands, prelude::*};
+use bevy::{
+    ecs::system::EntityCommands,
+    input::{
+        gamepad::{Gamepads, GamepadAxis, GamepadAxisType},
+        mouse::{MouseMotion, MouseWheel},
+    },
+    prelude::*,
+    render::{
+        mesh::{Indices, VertexAttributeValues},
+        pipeline::PrimitiveTopology,
+    },
+};
 use bevy_atmosphere::*;
+use hexasphere::shapes::IcoSphere;
 use heron::prelude::*;
-use ringbuffer::{ConstGenericRingBuffer, RingBufferExt, RingBufferWrite};
+use noise::{NoiseFn, Perlin, Seedable};
+use ringbuffer::{ConstGenericRingBuffer, RingBuffer, RingBufferExt, RingBufferWrite};
 
 #[bevy_main]
 fn main() {
@@ -14,14 +27,247 @@ fn main() {
             vsync: true,
             ..Default::default()
         })
-        .insert_resource(Gravity::from(Vec3::new(0.0, -9.81, 0.0)))
+        .insert_resource(Gravity::from(Vec3::ZERO))
+        .insert_resource(PlanetGravity::default())
+        .insert_resource(PlanetParams::default())
         .insert_resource(ParticleParams::default())
+        .insert_resource(InputState::default())
+        .insert_resource(MovementSettings::default())
         .add_startup_system(setup)
-        .add_system(particles)
+        .add_system(gather_input.before("player_movement"))
+        .add_system(player_movement.label("player_movement").before("particles"))
+        .add_system(planet_gravity.before("particles"))
+        .add_system(particles.label("particles"))
         .add_system(daylight_cycle)
+        .add_system(follow_camera)
+        .add_system_to_stage(CoreStage::PostUpdate, swept_collision.label("swept_collision"))
+        .add_system_to_stage(
+            CoreStage::PostUpdate,
+            record_previous_transform.after("swept_collision"),
+        )
         .run();
 }
 
+/// Masks raycasts and collisions to a specific kind of geometry.
+#[derive(PhysicsLayer)]
+enum Layer {
+    Terrain,
+    Particle,
+}
+
+/// Pulls dynamic rigid bodies toward a point instead of heron's flat directional `Gravity`.
+struct PlanetGravity {
+    center: Vec3,
+    strength: f32,
+    radius: f32,
+}
+
+impl Default for PlanetGravity {
+    fn default() -> Self {
+        Self {
+            center: Vec3::ZERO,
+            strength: 9.81,
+            radius: 100.0,
+        }
+    }
+}
+
+/// Marks the entity the camera should track (the player emitter).
+#[derive(Component)]
+struct CameraTarget;
+
+/// Orbit/follow state: distance and height above the target, plus mouse-driven yaw/pitch.
+#[derive(Component)]
+struct CameraController {
+    distance: f32,
+    height: f32,
+    yaw: f32,
+    pitch: f32,
+}
+
+impl Default for CameraController {
+    fn default() -> Self {
+        Self {
+            distance: 15.0,
+            height: 5.0,
+            yaw: 0.0,
+            pitch: 0.0,
+        }
+    }
+}
+
+/// Keeps the camera behind and above `CameraTarget`, using the planet surface normal as
+/// "up" so the horizon stays level anywhere on the globe.
+fn follow_camera(
+    planet: Res<PlanetGravity>,
+    mut scroll_events: EventReader<MouseWheel>,
+    mut motion_events: EventReader<MouseMotion>,
+    mouse_button: Res<Input<MouseButton>>,
+    target_query: Query<&Transform, (With<CameraTarget>, Without<CameraController>)>,
+    mut camera_query: Query<(&mut Transform, &mut CameraController)>,
+) {
+    let target = match target_query.single() {
+        Ok(target) => target,
+        Err(_) => return,
+    };
+    let (mut camera_transform, mut controller) = match camera_query.single_mut() {
+        Ok(camera) => camera,
+        Err(_) => return,
+    };
+
+    for scroll in scroll_events.iter() {
+        controller.distance = (controller.distance - scroll.y * 2.0).clamp(5.0, 150.0);
+    }
+
+    if mouse_button.pressed(MouseButton::Right) {
+        for motion in motion_events.iter() {
+            controller.yaw -= motion.delta.x * 0.005;
+            controller.pitch = (controller.pitch - motion.delta.y * 0.005).clamp(-1.4, 1.4);
+        }
+    } else {
+        // Drain so a held-up backlog of motion doesn't jerk the camera when the button is next pressed.
+        motion_events.iter().for_each(drop);
+    }
+
+    let up = (target.translation - planet.center).normalize_or_zero();
+    let (right, forward) = tangent_basis(up);
+
+    let orbit =
+        Quat::from_axis_angle(up, controller.yaw) * Quat::from_axis_angle(right, controller.pitch);
+    let back = orbit * -forward;
+
+    let translation = target.translation + back * controller.distance + up * controller.height;
+    *camera_transform = Transform::from_translation(translation).looking_at(target.translation, up);
+}
+
+/// Builds an arbitrary orthonormal basis (right, forward) perpendicular to `up`.
+fn tangent_basis(up: Vec3) -> (Vec3, Vec3) {
+    let reference = if up.dot(Vec3::Y).abs() < 0.99 {
+        Vec3::Y
+    } else {
+        Vec3::X
+    };
+    let right = up.cross(reference).normalize();
+    let forward = right.cross(up).normalize();
+    (right, forward)
+}
+
+/// The user-controlled particle emitter.
+#[derive(Component)]
+struct Player;
+
+/// Per-frame movement intent, merged from keyboard and gamepad.
+#[derive(Debug, Default)]
+struct InputState {
+    thrust: f32,
+    turn: f32,
+}
+
+/// Tuning knobs for how the player accelerates and turns around the planet.
+struct MovementSettings {
+    accel: f32,
+    max_speed: f32,
+    turn_rate: f32,
+}
+
+impl Default for MovementSettings {
+    fn default() -> Self {
+        Self {
+            accel: 20.0,
+            max_speed: 15.0,
+            turn_rate: 2.0,
+        }
+    }
+}
+
+fn gather_input(
+    keyboard: Res<Input<KeyCode>>,
+    gamepads: Res<Gamepads>,
+    axes: Res<Axis<GamepadAxis>>,
+    mut input: ResMut<InputState>,
+) {
+    let mut thrust = 0.0;
+    let mut turn = 0.0;
+
+    if keyboard.pressed(KeyCode::W) || keyboard.pressed(KeyCode::Up) {
+        thrust += 1.0;
+    }
+    if keyboard.pressed(KeyCode::S) || keyboard.pressed(KeyCode::Down) {
+        thrust -= 1.0;
+    }
+    if keyboard.pressed(KeyCode::A) || keyboard.pressed(KeyCode::Left) {
+        turn += 1.0;
+    }
+    if keyboard.pressed(KeyCode::D) || keyboard.pressed(KeyCode::Right) {
+        turn -= 1.0;
+    }
+
+    if let Some(&gamepad) = gamepads.iter().next() {
+        let stick_x = axes
+            .get(GamepadAxis(gamepad, GamepadAxisType::LeftStickX))
+            .unwrap_or(0.0);
+        let stick_y = axes
+            .get(GamepadAxis(gamepad, GamepadAxisType::LeftStickY))
+            .unwrap_or(0.0);
+        thrust += stick_y;
+        turn -= stick_x;
+    }
+
+    input.thrust = thrust.clamp(-1.0, 1.0);
+    input.turn = turn.clamp(-1.0, 1.0);
+}
+
+/// Steers the player around the globe: thrust is tangent to the surface, turning yaws
+/// around the local up axis.
+fn player_movement(
+    time: Res<Time>,
+    planet: Res<PlanetGravity>,
+    settings: Res<MovementSettings>,
+    input: Res<InputState>,
+    mut player: Query<(&mut Transform, &mut Velocity), With<Player>>,
+) {
+    let dt = time.delta_seconds();
+    let (mut transform, mut velocity) = match player.single_mut() {
+        Ok(player) => player,
+        Err(_) => return,
+    };
+
+    let up = (transform.translation - planet.center).normalize_or_zero();
+
+    let yaw = Quat::from_axis_angle(up, input.turn * settings.turn_rate * dt);
+    transform.rotation = yaw * transform.rotation;
+
+    let forward = transform.rotation * -Vec3::Z;
+    let forward_tangent = (forward - up * forward.dot(up)).normalize_or_zero();
+
+    let radial = up * velocity.linear.dot(up);
+    let mut tangential = velocity.linear - radial;
+    tangential += forward_tangent * input.thrust * settings.accel * dt;
+    if tangential.length() > settings.max_speed {
+        tangential = tangential.normalize() * settings.max_speed;
+    }
+    velocity.linear = tangential + radial;
+}
+
+fn planet_gravity(
+    gravity: Res<PlanetGravity>,
+    time: Res<Time>,
+    mut bodies: Query<(&RigidBody, &Transform, &mut Velocity)>,
+) {
+    let dt = time.delta_seconds();
+    for (body, transform, mut velocity) in bodies.iter_mut() {
+        if *body != RigidBody::Dynamic {
+            continue;
+        }
+        let offset = gravity.center - transform.translation;
+        // Outside the sphere of influence, let things coast instead of pulling forever.
+        if offset.length() > gravity.radius * 3.0 {
+            continue;
+        }
+        velocity.linear += offset.normalize_or_zero() * gravity.strength * dt;
+    }
+}
+
 #[derive(Debug, Default)]
 struct ParticleParams {
     radius: f32,
@@ -35,14 +281,115 @@ const MAX_PARTICLES: usize = 512;
 #[derive(Component)]
 struct Particle;
 
-fn particles(mut commands: Commands, mut params: ResMut<ParticleParams>) {
+/// A particle's transform as of the start of the frame, so `swept_collision` can tell
+/// how far it actually traveled.
+#[derive(Component, Default)]
+struct PreviousTransform(Transform);
+
+/// Counts down while a body eases back off a surface it was clamped onto this frame.
+#[derive(Component)]
+struct Tunneling {
+    frames: usize,
+    dir: Vec3,
+}
+
+/// Runs after `swept_collision` each frame, storing the post-physics transform for
+/// comparison next frame.
+fn record_previous_transform(mut bodies: Query<(&Transform, &mut PreviousTransform), With<Particle>>) {
+    for (transform, mut previous) in bodies.iter_mut() {
+        previous.0 = *transform;
+    }
+}
+
+/// Raycasts each particle's frame-to-frame motion against the terrain and clamps it to
+/// the first surface it would have hit, so fast particles don't tunnel through thin geometry.
+fn swept_collision(
+    physics_world: PhysicsWorld,
+    mut commands: Commands,
+    mut bodies: Query<
+        (
+            Entity,
+            &mut Transform,
+            &CollisionShape,
+            &PreviousTransform,
+            Option<&mut Tunneling>,
+        ),
+        With<Particle>,
+    >,
+) {
+    for (entity, mut transform, shape, previous, tunneling) in bodies.iter_mut() {
+        let radius = match shape {
+            CollisionShape::Sphere { radius } => *radius,
+            _ => continue,
+        };
+
+        let from = previous.0.translation;
+        let travel = transform.translation - from;
+        let distance = travel.length();
+
+        if distance > radius {
+            let hit = physics_world.ray_cast_with_filter(
+                from,
+                travel,
+                true,
+                CollisionLayers::none()
+                    .with_group(Layer::Particle)
+                    .with_mask(Layer::Terrain),
+                |hit_entity| hit_entity != entity,
+            );
+            if let Some(hit) = hit {
+                if hit.collision_point.distance(from) < distance {
+                    transform.translation = hit.collision_point;
+                    commands.entity(entity).insert(Tunneling {
+                        frames: 15,
+                        dir: hit.collision_normal,
+                    });
+                    continue;
+                }
+            }
+        }
+
+        if let Some(mut tunneling) = tunneling {
+            transform.translation += tunneling.dir * radius * 0.1;
+            tunneling.frames = tunneling.frames.saturating_sub(1);
+            if tunneling.frames == 0 {
+                commands.entity(entity).remove::<Tunneling>();
+            }
+        }
+    }
+}
+
+/// Once the ring buffer is full, recycle the oldest particle's `Entity` in place instead
+/// of despawning and respawning it.
+fn particles(
+    mut commands: Commands,
+    mut params: ResMut<ParticleParams>,
+    planet: Res<PlanetGravity>,
+    player: Query<&Transform, (With<Player>, Without<Particle>)>,
+    mut existing: Query<(&mut Transform, &mut Velocity, &mut PreviousTransform), With<Particle>>,
+) {
+    let emitter = match player.single() {
+        Ok(player) => player.translation,
+        Err(_) => return,
+    };
+    let up = (emitter - planet.center).normalize_or_zero();
+
     for _ in 0..1 {
-        if let Some(&entity) = params.ringbuffer.get(0) {
-            commands.get_or_spawn(entity).despawn_recursive();
+        if params.ringbuffer.is_full() {
+            let entity = *params.ringbuffer.get(0).expect("ring buffer is full");
+            if let Ok((mut transform, mut velocity, mut previous)) = existing.get_mut(entity) {
+                let (transform_reset, velocity_reset) = random_particle_state(emitter, up);
+                *transform = transform_reset;
+                *velocity = velocity_reset;
+                previous.0 = transform_reset;
+            }
+            commands.entity(entity).remove::<Tunneling>();
+            params.ringbuffer.push(entity);
+        } else {
+            let mut e = commands.spawn();
+            spawn_particles(&mut e, &params, emitter, up);
+            params.ringbuffer.push(e.id());
         }
-        let mut e = commands.spawn();
-        spawn_particles(&mut e, &params);
-        params.ringbuffer.push(e.id());
     }
 }
 /// set up a simple 3D scene
@@ -51,6 +398,7 @@ fn setup(
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     mut particle: ResMut<ParticleParams>,
+    planet: Res<PlanetParams>,
 ) {
     particle.radius = 0.05;
     particle.mesh = meshes.add(Mesh::from(shape::Icosphere {
@@ -66,7 +414,7 @@ fn setup(
         ..Default::default()
     });
 
-    spawn_ground(&mut commands, &mut meshes, &mut materials);
+    spawn_ground(&mut commands, &mut meshes, &mut materials, &planet);
 
     let size = 500.0;
 
@@ -91,16 +439,72 @@ fn setup(
             ..Default::default()
         })
         .insert(Sun);
-    commands.spawn_bundle(PerspectiveCameraBundle {
-        transform: Transform::from_xyz(-100.0, 50.0, -100.0)
-            .looking_at(Vec3::new(0.0, 30.0, 0.0), Vec3::Y),
-        ..Default::default()
-    });
+
+    commands
+        .spawn_bundle(PbrBundle {
+            mesh: meshes.add(Mesh::from(shape::Icosphere {
+                radius: 1.0,
+                subdivisions: 2,
+            })),
+            material: materials.add(StandardMaterial {
+                base_color: Color::rgb(0.2, 0.6, 0.9),
+                perceptual_roughness: 0.4,
+                ..Default::default()
+            }),
+            transform: Transform::from_xyz(0.0, planet.radius + 2.0, 0.0),
+            ..Default::default()
+        })
+        .insert(RigidBody::Dynamic)
+        .insert(CollisionShape::Sphere { radius: 1.0 })
+        .insert(PhysicMaterial {
+            restitution: 0.1,
+            density: 1.0,
+            friction: 0.5,
+        })
+        .insert(Velocity::default())
+        .insert(CameraTarget)
+        .insert(Player);
+
+    commands
+        .spawn_bundle(PerspectiveCameraBundle {
+            transform: Transform::from_xyz(-100.0, 50.0, -100.0)
+                .looking_at(Vec3::new(0.0, 30.0, 0.0), Vec3::Y),
+            ..Default::default()
+        })
+        .insert(CameraController::default());
 }
 
-fn spawn_particles(commands: &mut EntityCommands, particle: &ResMut<ParticleParams>) {
+/// Rolls a fresh spawn transform and launch velocity, shared by spawn and recycle paths.
+fn random_particle_state(origin: Vec3, up: Vec3) -> (Transform, Velocity) {
     let scale = 1.0 + fastrand::f32();
     let spread = 0.1;
+    let (right, forward) = tangent_basis(up);
+
+    let jitter = right * (fastrand::f32() * spread - spread / 2.0)
+        + forward * (fastrand::f32() * spread - spread / 2.0)
+        + up * (fastrand::f32() * spread - spread / 2.0);
+
+    let transform = Transform {
+        translation: origin + up * 1.5 + jitter,
+        scale: Vec3::splat(scale),
+        ..Default::default()
+    };
+
+    let horizontal =
+        right * (fastrand::f32() - 0.5) * 50.0 + forward * (fastrand::f32() - 0.5) * 50.0;
+    let velocity = Velocity::from_linear(horizontal - up * 5.0);
+
+    (transform, velocity)
+}
+
+fn spawn_particles(
+    commands: &mut EntityCommands,
+    particle: &ResMut<ParticleParams>,
+    origin: Vec3,
+    up: Vec3,
+) {
+    let (transform, velocity) = random_particle_state(origin, up);
+    let scale = transform.scale.x;
     commands
         .insert_bundle(PbrBundle {
             mesh: particle.mesh.clone(),
@@ -118,6 +522,7 @@ fn spawn_particles(commands: &mut EntityCommands, particle: &ResMut<ParticlePara
             density: 0.01,
             friction: 0.1,
         })
+        .insert(CollisionLayers::none().with_group(Layer::Particle).with_mask(Layer::Terrain))
         .insert_bundle(PointLightBundle {
             point_light: PointLight {
                 intensity: 10000.0 * scale * particle.radius,
@@ -128,27 +533,142 @@ fn spawn_particles(commands: &mut EntityCommands, particle: &ResMut<ParticlePara
             },
             ..Default::default()
         })
-        .insert(Transform {
-            translation: Vec3::new(
-                fastrand::f32() * spread - spread / 2.0,
-                fastrand::f32() * spread - spread / 2.0 + 40.0,
-                fastrand::f32() * spread - spread / 2.0,
-            ),
-            scale: Vec3::splat(scale),
-            ..Default::default()
-        })
-        .insert(Velocity::from_linear(Vec3::new(
-            (fastrand::f32() - 0.5) * 50.0,
-            -5.0,
-            (fastrand::f32() - 0.5) * 50.0,
-        )))
+        .insert(transform)
+        .insert(velocity)
+        .insert(PreviousTransform(transform))
         .insert(Particle);
 }
 
+/// Reproducible tuning knobs for the planet's terrain generation.
+struct PlanetParams {
+    radius: f32,
+    seed: u32,
+    subdivisions: usize,
+    octaves: u32,
+    base_frequency: f64,
+    amplitude: f32,
+}
+
+impl Default for PlanetParams {
+    fn default() -> Self {
+        Self {
+            radius: 100.0,
+            seed: 0,
+            subdivisions: 8,
+            octaves: 5,
+            base_frequency: 1.5,
+            amplitude: 0.08,
+        }
+    }
+}
+
+/// Layered (fractal) Perlin noise: smaller, higher-frequency wrinkles on top of the last.
+fn fbm(noise: &Perlin, point: Vec3, octaves: u32, base_frequency: f64) -> f32 {
+    let mut amplitude = 1.0;
+    let mut frequency = base_frequency;
+    let mut sum = 0.0;
+    let mut max = 0.0;
+    for _ in 0..octaves {
+        let sample = point.as_dvec3() * frequency;
+        sum += noise.get([sample.x, sample.y, sample.z]) * amplitude;
+        max += amplitude;
+        amplitude *= 0.5;
+        frequency *= 2.0;
+    }
+    (sum / max) as f32
+}
+
+/// Smooth per-vertex normals for indexed geometry (`compute_flat_normals` panics on it).
+fn compute_smooth_normals(positions: &[[f32; 3]], indices: &[u32]) -> Vec<[f32; 3]> {
+    let mut normals = vec![Vec3::ZERO; positions.len()];
+    for triangle in indices.chunks_exact(3) {
+        let [a, b, c] = [triangle[0] as usize, triangle[1] as usize, triangle[2] as usize];
+        let pa = Vec3::from(positions[a]);
+        let pb = Vec3::from(positions[b]);
+        let pc = Vec3::from(positions[c]);
+        let face_normal = (pb - pa).cross(pc - pa);
+        normals[a] += face_normal;
+        normals[b] += face_normal;
+        normals[c] += face_normal;
+    }
+    normals
+        .into_iter()
+        .map(|normal| normal.normalize_or_zero().into())
+        .collect()
+}
+
+/// Builds a displaced, noise-textured icosphere mesh, plus the per-face index ranges
+/// `collision_shape_from_mesh` needs to build a matching collider.
+fn generate_planet_mesh(planet: &PlanetParams) -> (Mesh, Vec<std::ops::Range<usize>>) {
+    let sphere = IcoSphere::new(planet.subdivisions, |_| ());
+    let noise = Perlin::new().set_seed(planet.seed);
+
+    let positions: Vec<[f32; 3]> = sphere
+        .raw_points()
+        .iter()
+        .map(|&p| {
+            let unit = Vec3::new(p.x, p.y, p.z);
+            let displacement = fbm(&noise, unit, planet.octaves, planet.base_frequency);
+            let point = unit * planet.radius * (1.0 + planet.amplitude * displacement);
+            [point.x, point.y, point.z]
+        })
+        .collect();
+
+    let mut indices = Vec::new();
+    let mut face_ranges = Vec::with_capacity(20);
+    for face in 0..20 {
+        let start = indices.len();
+        sphere.get_indices(face, &mut indices);
+        face_ranges.push(start..indices.len());
+    }
+
+    let normals = compute_smooth_normals(&positions, &indices);
+
+    let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+    mesh.set_attribute(Mesh::ATTRIBUTE_UV_0, vec![[0.0, 0.0]; positions.len()]);
+    mesh.set_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    mesh.set_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.set_indices(Some(Indices::U32(indices)));
+    (mesh, face_ranges)
+}
+
+/// Heron has no generic trimesh shape, so the collider is a compound of one convex hull
+/// per icosahedron face (20 total), not per triangle - a lone triangle is a degenerate hull.
+fn collision_shape_from_mesh(mesh: &Mesh, face_ranges: &[std::ops::Range<usize>]) -> CollisionShape {
+    let positions = match mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
+        Some(VertexAttributeValues::Float32x3(positions)) => positions.clone(),
+        _ => Vec::new(),
+    };
+    let indices = match mesh.indices() {
+        Some(Indices::U32(indices)) => indices.clone(),
+        _ => Vec::new(),
+    };
+
+    let hulls = face_ranges
+        .iter()
+        .map(|range| {
+            let points = indices[range.clone()]
+                .iter()
+                .map(|&index| Vec3::from(positions[index as usize]))
+                .collect();
+            (
+                Transform::default(),
+                CollisionShape::ConvexHull {
+                    points,
+                    border_radius: None,
+                },
+            )
+        })
+        .collect();
+
+    CollisionShape::Compound(hulls)
+}
+
 fn spawn_ground(
     commands: &mut Commands,
     meshes: &mut ResMut<Assets<Mesh>>,
     materials: &mut ResMut<Assets<StandardMaterial>>,
+    planet: &PlanetParams,
 ) {
     let material = materials.add(StandardMaterial {
         base_color: Color::rgb(0.5, 0.5, 0.5),
@@ -157,59 +677,28 @@ fn spawn_ground(
         reflectance: 0.5,
         ..Default::default()
     });
+
+    let (mesh, face_ranges) = generate_planet_mesh(planet);
+    let collision_shape = collision_shape_from_mesh(&mesh, &face_ranges);
+
     commands
         .spawn()
-        .insert(Transform::from_xyz(0.0, -100.0, 0.0))
+        .insert(Transform::default())
         .insert(GlobalTransform::default())
-        .insert(CollisionShape::Cuboid {
-            half_extends: Vec3::new(50.0, 100.0, 50.0),
-            border_radius: None,
-        })
-        .insert(RigidBody::Static) // Attach a collision shape
+        .insert(collision_shape)
+        .insert(RigidBody::Static)
         .insert(PhysicMaterial {
             restitution: 0.5,
             ..Default::default()
         })
+        .insert(CollisionLayers::none().with_group(Layer::Terrain).with_mask(Layer::Particle))
         .with_children(|child| {
             child.spawn_bundle(PbrBundle {
-                mesh: meshes.add(Mesh::from(shape::Plane { size: 10000.0 })),
+                mesh: meshes.add(mesh),
                 material: material.clone(),
-                transform: Transform::from_xyz(0.0, 100.0, 0.0),
                 ..Default::default()
             });
         });
-
-    let obstacle_mesh = meshes.add(Mesh::from(shape::Cube { size: 1.0 }));
-
-    for _ in 0..1000 {
-        let height = fastrand::f32() * 20.0;
-        commands
-            .spawn_bundle(PbrBundle {
-                mesh: obstacle_mesh.clone(),
-                material: material.clone(),
-                transform: Transform {
-                    translation: Vec3::new(
-                        fastrand::f32() * 500.0 - 250.0,
-                        height / 2.0,
-                        fastrand::f32() * 500.0 - 250.0,
-                    ),
-                    scale: Vec3::new(5.0, height, 5.0),
-                    ..Default::default()
-                },
-                ..Default::default()
-            })
-            .insert(RigidBody::Static)
-            .insert(CollisionShape::Cuboid {
-                // let the size be consistent with our sprite
-                half_extends: Vec3::new(5.0 / 2.0, height / 2.0, 5.0 / 2.0),
-                border_radius: None,
-            })
-            .insert(PhysicMaterial {
-                restitution: 0.9,
-                density: 1.0,
-                friction: 0.1,
-            });
-    }
 }
 
 // Marker for updating the position of the light, not needed unless we have multiple lights